@@ -2,35 +2,43 @@ use std::borrow::Cow;
 use std::fs::File;
 use std::io::{self, BufRead};
 use std::path::Path;
-use std::convert::TryInto;
 use rayon::prelude::*;
 use indicatif::{ParallelProgressIterator, ProgressBar, ProgressStyle};
 use reedline::{Prompt, PromptEditMode, PromptHistorySearch, Reedline, Signal};
 
-#[derive(PartialEq, Eq, Clone, Copy)]
-struct Word([char; 5]);
+#[derive(PartialEq, Eq, Clone)]
+struct Word(Box<[char]>);
+
+impl Word {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
 
 impl std::fmt::Display for Word {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}{}{}{}{}", self.0[0], self.0[1], self.0[2], self.0[3], self.0[4])
+        for c in self.0.iter() {
+            write!(f, "{}", c)?;
+        }
+        Ok(())
     }
 }
 
 impl std::fmt::Debug for Word {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}{}{}{}{}", self.0[0], self.0[1], self.0[2], self.0[3], self.0[4])
+        write!(f, "{}", self)
     }
 }
 
 impl From<String> for Word {
     fn from(a: String) -> Self {
-        Word(a.chars().collect::<Vec<char>>().try_into().unwrap())
+        Word(a.chars().collect())
     }
 }
 
 impl From<&str> for Word {
     fn from(a: &str) -> Self {
-        Word(a.chars().collect::<Vec<char>>().try_into().unwrap())
+        Word(a.chars().collect())
     }
 }
 
@@ -41,112 +49,128 @@ enum Status {
     None,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 struct Match {
     word: Word,
-    status: [Status; 5],
-}
-
-fn find_in_word(word: Word, needle: char) -> Option<usize> {
-    for i in 0..5 {
-        if word.0[i] == needle {
-            return Some(i);
-        }
-    }
-
-    return None;
+    status: Box<[Status]>,
 }
 
 impl Match {
     fn new(word: Word) -> Self {
+        let len = word.len();
         Match {
             word,
-            status: [Status::None; 5]
+            status: vec![Status::None; len].into_boxed_slice(),
         }
     }
 
-    fn mask(res: &str) -> [Status; 5] {
-        let mut mat = Match::new(Word::from("panic"));
+    // Returns None rather than panicking on a mistyped mask (wrong length,
+    // or a character that isn't '=', '~', or '.') so the caller can
+    // re-prompt instead of crashing mid-game.
+    fn mask(res: &str, len: usize) -> Option<Box<[Status]>> {
+        if res.chars().count() != len {
+            return None;
+        }
+
+        let mut status = vec![Status::None; len].into_boxed_slice();
 
-        for i in 0..5 {
-            match res.chars().nth(i).unwrap() {
-                '=' => mat.status[i] = Status::Exact,
-                '~' => mat.status[i] = Status::Found,
-                '.' => mat.status[i] = Status::None,
-                _ => panic!("Unexpected character"),
+        for (i, c) in res.chars().enumerate() {
+            match c {
+                '=' => status[i] = Status::Exact,
+                '~' => status[i] = Status::Found,
+                '.' => status[i] = Status::None,
+                _ => return None,
             }
         }
 
-        mat.status
+        Some(status)
     }
 
-    fn input(word: Word, status: [Status; 5]) -> Self {
+    fn input(word: Word, status: Box<[Status]>) -> Self {
         Match {
             word,
             status,
         }
     }
 
-    fn compute(guess: Word, mut ans: Word) -> Self {
-        let mut mat = Match::new(guess);
+    // Tracks which letter positions have already been claimed by an Exact
+    // or Found match via a bitmask instead of cloning the word and
+    // sentinel-marking consumed letters with '.' — this runs once per
+    // guess/answer pair, so avoiding the extra allocation matters.
+    fn compute(guess: &Word, ans: &Word) -> Self {
+        let len = guess.len();
+        let mut mat = Match::new(guess.clone());
+        let mut used: u64 = 0;
 
-        for i in 0..5 {
+        for i in 0..len {
             if guess.0[i] == ans.0[i] {
                 mat.status[i] = Status::Exact;
-                ans.0[i] = '.';
+                used |= 1 << i;
             }
         }
 
-        for i in 0..5 {
-            if let Some(index) = find_in_word(ans, guess.0[i]) {
-                mat.status[i] = Status::Found;
-                ans.0[index] = '.';
+        for i in 0..len {
+            if matches!(mat.status[i], Status::Exact) {
+                continue;
+            }
+
+            for j in 0..len {
+                if used & (1 << j) == 0 && ans.0[j] == guess.0[i] {
+                    mat.status[i] = Status::Found;
+                    used |= 1 << j;
+                    break;
+                }
             }
         }
 
         mat
     }
 
-    fn valid(&self, mut word: Word) -> bool {
-        for i in 0..5 {
+    fn valid(&self, word: &Word) -> bool {
+        let len = self.word.len();
+        let mut used: u64 = 0;
+
+        for i in 0..len {
             match self.status[i] {
                 Status::Exact => {
                     if word.0[i] != self.word.0[i] {
                         return false;
-                    } else {
-                        word.0[i] = '.';
                     }
+                    used |= 1 << i;
                 }
-                Status::Found => {
-                    if word.0[i] == self.word.0[i] {
-                        return false;
-                    }
+                Status::Found
+                    if word.0[i] == self.word.0[i] => {
+                    return false;
                 }
                 _ => ()
             }
         }
 
-        for i in 0..5 {
-            match self.status[i] {
-                Status::Found => {
-                    if let Some(index) = find_in_word(word, self.word.0[i]) {
-                        word.0[index] = '.';
-                    } else {
-                        return false;
+        for i in 0..len {
+            if let Status::Found = self.status[i] {
+                let mut matched = false;
+
+                for j in 0..len {
+                    if used & (1 << j) == 0 && word.0[j] == self.word.0[i] {
+                        used |= 1 << j;
+                        matched = true;
+                        break;
                     }
                 }
-                _ => ()
+
+                if !matched {
+                    return false;
+                }
             }
         }
 
-        for i in 0..5 {
-            match self.status[i] {
-                Status::None => {
-                    if find_in_word(word, self.word.0[i]).is_some() {
+        for i in 0..len {
+            if let Status::None = self.status[i] {
+                for j in 0..len {
+                    if used & (1 << j) == 0 && word.0[j] == self.word.0[i] {
                         return false;
                     }
                 }
-                _ => ()
             }
         }
 
@@ -156,7 +180,7 @@ impl Match {
 
 impl std::fmt::Display for Match {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        for i in 0..5 {
+        for i in 0..self.word.len() {
             match self.status[i] {
                 Status::Exact => write!(f, "\x1B[32m{}\x1B[0m", self.word.0[i])?,
                 Status::Found => write!(f, "\x1B[33m{}\x1B[0m", self.word.0[i])?,
@@ -168,13 +192,16 @@ impl std::fmt::Display for Match {
     }
 }
 
-fn score(guess: Word, words: &Vec<Word>) -> usize {
-    words.par_iter()
-        .map(|ans| {
-            let mat = Match::compute(guess, *ans);
-            found(mat, words)
+// The answer list carries a (Word, frequency) weight for each candidate so
+// scoring can prefer plausible everyday answers over obscure ones that
+// merely tie on candidate-elimination power.
+fn score(guess: &Word, answers: &[(Word, f64)]) -> f64 {
+    answers.par_iter()
+        .map(|(ans, weight)| {
+            let mat = Match::compute(guess, ans);
+            weight * found(&mat, answers)
         })
-        .sum::<usize>()
+        .sum::<f64>()
 }
 
 // fn score_debug(guess: Word, words: &Vec<Word>) -> usize {
@@ -190,59 +217,253 @@ fn score(guess: Word, words: &Vec<Word>) -> usize {
 //         .sum::<usize>()
 // }
 
-fn found(res: Match, words: &Vec<Word>) -> usize {
-    let mut sum = 0;
+fn found(res: &Match, answers: &[(Word, f64)]) -> f64 {
+    answers.iter()
+        .filter(|(word, _)| res.valid(word))
+        .map(|(_, weight)| weight)
+        .sum()
+}
+
+fn filter(res: &Match, answers: &mut Vec<(Word, f64)>) {
+    answers.retain(|(word, _)| res.valid(word))
+}
 
-    for word in words {
-        if res.valid(*word) {
-            sum += 1;
+fn read_lines<P>(filename: P) -> io::Result<Vec<Word>>
+where P: AsRef<Path>, {
+    let file = File::open(filename)?;
+    let mut words = Vec::new();
+    let mut len = None;
+
+    for line in io::BufReader::new(file).lines() {
+        let word = Word::from(line?);
+
+        match len {
+            None => len = Some(word.len()),
+            Some(len) if word.len() != len => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("expected {}-letter words but found {:?}", len, word),
+                ));
+            }
+            _ => (),
         }
-    }
 
-    sum
-}
+        words.push(word);
+    }
 
-fn filter(res: Match, words: &mut Vec<Word>) {
-    words.retain(|x| res.valid(*x))
-        // .into_iter()
-        // .par_iter()
-        // .map(|x| *x)
-        // .filter(|x| res.valid(*x))
-        // .collect()
+    Ok(words)
 }
 
-fn read_lines<P>(filename: P) -> io::Result<Vec<Word>>
+// Like read_lines, but with an optional trailing frequency column per word.
+fn read_weighted_lines<P>(filename: P) -> io::Result<Vec<(Word, f64)>>
 where P: AsRef<Path>, {
     let file = File::open(filename)?;
-    Ok(
-        io::BufReader::new(file)
-            .lines()
-            .map(Result::unwrap)
-            .map(Word::from)
-            .collect())
+    let mut words = Vec::new();
+    let mut len = None;
+
+    for line in io::BufReader::new(file).lines() {
+        let line = line?;
+        let mut parts = line.split_whitespace();
+        let word = Word::from(parts.next().unwrap_or(""));
+        let weight = parts.next()
+            .and_then(|freq| freq.parse::<f64>().ok())
+            .unwrap_or(1.0);
+
+        match len {
+            None => len = Some(word.len()),
+            Some(len) if word.len() != len => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("expected {}-letter words but found {:?}", len, word),
+                ));
+            }
+            _ => (),
+        }
+
+        words.push((word, weight));
+    }
+
+    Ok(words)
 }
 
-fn best_word(full_words: &Vec<Word>, ans: &Vec<Word>) -> Word {
+fn weight_of(word: &Word, answers: &[(Word, f64)]) -> f64 {
+    answers.iter()
+        .find(|(candidate, _)| candidate == word)
+        .map(|(_, weight)| *weight)
+        .unwrap_or(0.0)
+}
+
+// The more frequent of a couple of final candidates.
+fn most_frequent(answers: &[(Word, f64)]) -> Word {
+    answers.iter()
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .unwrap().0.clone()
+}
+
+fn best_word(full_words: &[Word], answers: &[(Word, f64)]) -> Word {
     let bar = ProgressBar::new(full_words.len() as u64)
         .with_style(ProgressStyle::default_bar()
-            .template("[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} {msg}")
+            .template("[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} {msg}").unwrap()
             .progress_chars("##-"));
 
     full_words.par_iter()
         .progress_with(bar)
-        .map(|word| (*word, score(*word, &ans)))
+        .map(|word| (word.clone(), score(word, answers)))
         // .inspect(|x| println!("{} = {}", as_string(&x.0), x.1))
-        .min_by(|a, b| a.1.cmp(&b.1))
+        // ties prefer the higher-frequency guess
+        .min_by(|a, b| {
+            a.1.partial_cmp(&b.1)
+                .unwrap()
+                .then_with(|| weight_of(&b.0, answers).partial_cmp(&weight_of(&a.0, answers)).unwrap())
+        })
+        .unwrap().0
+}
+
+fn pattern_key(status: &[Status]) -> u32 {
+    status.iter().fold(0u32, |key, status| {
+        key * 3 + match status {
+            Status::Exact => 0,
+            Status::Found => 1,
+            Status::None => 2,
+        }
+    })
+}
+
+// Shannon entropy of the feedback pattern `guess` produces, weighted by
+// each answer's probability.
+fn entropy_score(guess: &Word, answers: &[(Word, f64)]) -> f64 {
+    let mut buckets: std::collections::HashMap<u32, f64> = std::collections::HashMap::new();
+    let mut total = 0.0;
+
+    for (ans, weight) in answers {
+        let mat = Match::compute(guess, ans);
+        *buckets.entry(pattern_key(&mat.status)).or_insert(0.0) += weight;
+        total += weight;
+    }
+
+    // A weighted wordlist may legally zero out every remaining candidate
+    // (to deprioritize rather than delete them) — fall back to counting
+    // candidates uniformly instead of dividing by a zero total.
+    if total == 0.0 {
+        buckets.clear();
+        for (ans, _) in answers {
+            let mat = Match::compute(guess, ans);
+            *buckets.entry(pattern_key(&mat.status)).or_insert(0.0) += 1.0;
+        }
+        total = answers.len() as f64;
+    }
+
+    buckets.values()
+        .map(|&weight| {
+            let p = weight / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+fn best_entropy_word(full_words: &[Word], answers: &[(Word, f64)]) -> Word {
+    let bar = ProgressBar::new(full_words.len() as u64)
+        .with_style(ProgressStyle::default_bar()
+            .template("[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} {msg}").unwrap()
+            .progress_chars("##-"));
+
+    full_words.par_iter()
+        .progress_with(bar)
+        .map(|word| (word.clone(), entropy_score(word, answers)))
+        // ties prefer the higher-frequency guess (non-answers weigh 0)
+        .max_by(|a, b| {
+            a.1.partial_cmp(&b.1)
+                .unwrap()
+                .then_with(|| weight_of(&a.0, answers).partial_cmp(&weight_of(&b.0, answers)).unwrap())
+        })
         .unwrap().0
 }
 
+// A pluggable guessing policy, picking the next word from the weighted
+// candidates still consistent with every mask seen so far.
+trait Solver: Sync {
+    fn make_a_move(&self, remaining: &[(Word, f64)], full: &[Word], guesses: usize) -> Word;
+}
+
+struct MinimizeRemaining;
+
+impl Solver for MinimizeRemaining {
+    fn make_a_move(&self, remaining: &[(Word, f64)], full: &[Word], guesses: usize) -> Word {
+        match remaining.len() {
+            _ if guesses == 0 => Word::from("roate"),
+            1 => remaining[0].0.clone(),
+            2 => most_frequent(remaining),
+            _ => best_word(full, remaining),
+        }
+    }
+}
+
+// Picks the word built from the letters most common among the remaining
+// candidates, ignoring candidate elimination entirely.
+struct MostCommonLetters;
+
+impl Solver for MostCommonLetters {
+    fn make_a_move(&self, remaining: &[(Word, f64)], full: &[Word], _guesses: usize) -> Word {
+        match remaining.len() {
+            1 => remaining[0].0.clone(),
+            2 => most_frequent(remaining),
+            _ => {
+                let mut freq: std::collections::HashMap<char, f64> = std::collections::HashMap::new();
+                for (word, weight) in remaining {
+                    for c in word.0.iter() {
+                        *freq.entry(*c).or_insert(0.0) += weight;
+                    }
+                }
+
+                full.iter()
+                    .map(|word| {
+                        let mut seen = std::collections::HashSet::new();
+                        let score = word.0.iter()
+                            .filter(|c| seen.insert(*c))
+                            .map(|c| freq.get(c).copied().unwrap_or(0.0))
+                            .sum::<f64>();
+                        (word.clone(), score)
+                    })
+                    .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                    .unwrap().0
+            }
+        }
+    }
+}
+
+// Scores guesses by expected information gain rather than candidates remaining.
+struct MaximizeEntropy;
+
+impl Solver for MaximizeEntropy {
+    fn make_a_move(&self, remaining: &[(Word, f64)], full: &[Word], guesses: usize) -> Word {
+        match remaining.len() {
+            _ if guesses == 0 => Word::from("roate"),
+            1 => remaining[0].0.clone(),
+            2 => most_frequent(remaining),
+            _ => best_entropy_word(full, remaining),
+        }
+    }
+}
+
+fn make_solver(name: &str) -> Box<dyn Solver> {
+    match name {
+        "frequency" => Box::new(MostCommonLetters),
+        "entropy" => Box::new(MaximizeEntropy),
+        _ => Box::new(MinimizeRemaining),
+    }
+}
+
 struct EmptyPrompt;
 
 impl Prompt for EmptyPrompt {
-    fn render_prompt(&self, _: usize) -> Cow<'_, str> {
+    fn render_prompt_left(&self) -> Cow<'_, str> {
         Cow::from("> ")
     }
 
+    fn render_prompt_right(&self) -> Cow<'_, str> {
+        Cow::from("")
+    }
+
     fn render_prompt_indicator(&self, _prompt_mode: PromptEditMode) -> Cow<'_, str> {
         Cow::from("")
     }
@@ -250,9 +471,9 @@ impl Prompt for EmptyPrompt {
     fn render_prompt_multiline_indicator(&self) -> Cow<'_, str> {
         Cow::from("")
     }
-    
+
     fn render_prompt_history_search_indicator(
-        &self, 
+        &self,
         _history_search: PromptHistorySearch
     ) -> Cow<'_, str> {
         Cow::from("")
@@ -265,80 +486,259 @@ fn main() {
         .build_global()
         .unwrap();
 
-    let mut answers = read_lines("answer_words.txt").unwrap();
-    let full_words = read_lines("wordle.txt").unwrap();
-
-    // let total = answers.par_iter()
-    //     // .progress_count(answers.len() as u64)
-    //     .map(|word| (word, simulate(*word, &full_words, &answers)))
-    //     .inspect(|(word, count)| {
-    //         if *count > 6 {
-    //             println!("{} => \x1B[1;31m{}\x1B[0m", word, count)
-    //         } else {
-    //             println!("{} => {}", word, count)
-    //         }
-    //     })
-    //     .map(|(_, count)| count)
-    //     .sum::<u64>();
-
-    // println!("Average words taken: {}", total as f64 / answers.len() as f64);
-
-    let mut line_editor = Reedline::create().unwrap();
+    let mut answers = read_weighted_lines("answer_words.txt").unwrap();
+    let mut full_words = read_lines("wordle.txt").unwrap();
+
+    let mut strategy = "minimize".to_string();
+    let mut max_steps = 10usize;
+    let mut run_benchmark = false;
+
+    let mut cli_args = std::env::args().skip(1);
+    while let Some(arg) = cli_args.next() {
+        match arg.as_str() {
+            "--strategy" => strategy = cli_args.next().expect("--strategy needs a value"),
+            "--max-steps" => max_steps = cli_args.next()
+                .expect("--max-steps needs a value")
+                .parse()
+                .expect("--max-steps takes a number"),
+            "benchmark" => run_benchmark = true,
+            _ => (),
+        }
+    }
+
+    let solver = make_solver(&strategy);
+
+    if run_benchmark {
+        let report = benchmark(&full_words, &answers, &*solver, max_steps);
+        println!("{}", report);
+        return;
+    }
+
+    let mut line_editor = Reedline::create();
     let prompt = EmptyPrompt;
 
-    guesser(&full_words, &answers, move |word| {
+    play_interactive(&mut full_words, &mut answers, &*solver, move |word| {
         print!("< {}", word);
-        let input = line_editor.read_line(&prompt).unwrap();
-
-        match input {
-            Signal::Success(buffer) => Match::mask(&buffer),
-            _ => panic!("Exiting"),
+        match line_editor.read_line(&prompt).unwrap() {
+            Signal::Success(buffer) => buffer,
+            Signal::CtrlC | Signal::CtrlD => {
+                println!("Exiting...");
+                std::process::exit(0);
+            }
         }
     });
 }
 
-fn simulate(true_ans: Word, full_words: &Vec<Word>, answers: &Vec<Word>) -> u64 {
-    guesser(full_words, answers, move |word| Match::compute(word, true_ans).status) as u64
+// A parsed line of REPL input.
+enum Command {
+    Guess(Box<[Status]>),
+    Undo(usize),
+    New,
+    MarkInvalid,
+    Malformed,
 }
 
-fn guesser(full_words: &Vec<Word>, answers: &Vec<Word>, mut program: impl FnMut(Word) -> [Status; 5]) -> usize {
-    let mut answers = answers.clone();
-    let mut last_word = Word::from("roate");
-    // let mut last_word = best_word(&answers, &answers);
+fn parse_command(input: &str, len: usize) -> Command {
+    let input = input.trim();
+
+    if input == "new" {
+        return Command::New;
+    }
+
+    if input == "invalid" {
+        return Command::MarkInvalid;
+    }
+
+    if let Some(rest) = input.strip_prefix("undo") {
+        let n = rest.trim().parse().unwrap_or(1);
+        return Command::Undo(n);
+    }
+
+    match Match::mask(input, len) {
+        Some(mask) => Command::Guess(mask),
+        None => Command::Malformed,
+    }
+}
+
+// Live play loop with undo/new/invalid recovery commands.
+fn play_interactive(
+    full_words: &mut Vec<Word>,
+    answers: &mut Vec<(Word, f64)>,
+    solver: &dyn Solver,
+    mut read_line: impl FnMut(Word) -> String,
+) {
+    let mut history: Vec<(Vec<(Word, f64)>, Word)> = Vec::new();
     let mut guesses = 0;
+    let mut last_word = solver.make_a_move(answers, full_words, guesses);
 
     loop {
-        // print!("< {}", last_word);
-        // let input: String = read!("{}");
-        // let input = line_editor.read_line(&prompt).unwrap();
+        let input = read_line(last_word.clone());
+
+        match parse_command(&input, last_word.len()) {
+            Command::New => {
+                history.clear();
+                guesses = 0;
+                *answers = full_words.iter().map(|word| (word.clone(), 1.0)).collect();
+                last_word = solver.make_a_move(answers, full_words, guesses);
+            }
+            Command::Undo(n) => {
+                for _ in 0..n {
+                    match history.pop() {
+                        Some((snapshot, word)) => {
+                            *answers = snapshot;
+                            last_word = word;
+                            guesses -= 1;
+                        }
+                        None => break,
+                    }
+                }
+            }
+            Command::MarkInvalid => {
+                full_words.retain(|word| *word != last_word);
+                answers.retain(|(word, _)| *word != last_word);
+                last_word = solver.make_a_move(answers, full_words, guesses);
+            }
+            Command::Malformed => {
+                println!("Expected {} characters of '=', '~', or '.' — try again.", last_word.len());
+            }
+            Command::Guess(mask) => {
+                history.push((answers.clone(), last_word.clone()));
+                guesses += 1;
+                filter(&Match::input(last_word.clone(), mask.clone()), answers);
+
+                if mask.iter().all(|status| matches!(status, Status::Exact)) {
+                    println!("Solved in {} guesses!", guesses);
+                    return;
+                }
 
+                if answers.is_empty() {
+                    *answers = full_words.iter().map(|word| (word.clone(), 1.0)).collect();
+                }
+
+                last_word = solver.make_a_move(answers, full_words, guesses);
+            }
+        }
+    }
+}
+
+fn simulate(
+    true_ans: Word,
+    full_words: &[Word],
+    answers: &[(Word, f64)],
+    solver: &dyn Solver,
+    max_steps: usize,
+) -> Option<usize> {
+    guesser(full_words, answers, solver, max_steps, move |word| Match::compute(&word, &true_ans).status)
+}
+
+// Summary stats across every answer run through `simulate`.
+struct BenchmarkReport {
+    max_steps: usize,
+    histogram: Vec<u64>,
+    mean_guesses: Option<f64>,
+    worst_words: Vec<Word>,
+    win_rate: f64,
+}
+
+impl std::fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "win rate: {:.2}%", self.win_rate * 100.0)?;
+
+        match self.mean_guesses {
+            Some(mean) => writeln!(f, "mean guesses (wins only): {:.3}", mean)?,
+            None => writeln!(f, "mean guesses (wins only): no wins")?,
+        }
+
+        for (i, count) in self.histogram.iter().enumerate() {
+            if *count > 0 {
+                writeln!(f, "  {} guesses: {}", i + 1, count)?;
+            }
+        }
+
+        if self.worst_words.is_empty() {
+            return write!(f, "worst case (within {} guesses): none", self.max_steps);
+        }
+
+        write!(
+            f,
+            "worst case (within {} guesses): {}",
+            self.max_steps,
+            self.worst_words.iter().map(Word::to_string).collect::<Vec<_>>().join(", "),
+        )
+    }
+}
+
+fn benchmark(full_words: &[Word], answers: &[(Word, f64)], solver: &dyn Solver, max_steps: usize) -> BenchmarkReport {
+    let bar = ProgressBar::new(answers.len() as u64)
+        .with_style(ProgressStyle::default_bar()
+            .template("[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} {msg}").unwrap()
+            .progress_chars("##-"));
+
+    let results: Vec<(Word, Option<usize>)> = answers.par_iter()
+        .progress_with(bar)
+        .map(|(ans, _)| (ans.clone(), simulate(ans.clone(), full_words, answers, solver, max_steps)))
+        .collect();
+
+    let mut histogram = vec![0u64; max_steps];
+    let mut wins = 0u64;
+    let mut total_guesses = 0u64;
+    let mut worst = 0usize;
+    let mut worst_words = Vec::new();
+
+    for (word, outcome) in &results {
+        if let Some(guesses) = outcome {
+            histogram[guesses - 1] += 1;
+            wins += 1;
+            total_guesses += *guesses as u64;
+
+            match guesses.cmp(&worst) {
+                std::cmp::Ordering::Greater => {
+                    worst = *guesses;
+                    worst_words = vec![word.clone()];
+                }
+                std::cmp::Ordering::Equal => worst_words.push(word.clone()),
+                std::cmp::Ordering::Less => (),
+            }
+        }
+    }
+
+    BenchmarkReport {
+        max_steps,
+        histogram,
+        mean_guesses: (wins > 0).then(|| total_guesses as f64 / wins as f64),
+        worst_words,
+        win_rate: wins as f64 / results.len() as f64,
+    }
+}
+
+fn guesser(
+    full_words: &[Word],
+    answers: &[(Word, f64)],
+    solver: &dyn Solver,
+    max_steps: usize,
+    mut program: impl FnMut(Word) -> Box<[Status]>,
+) -> Option<usize> {
+    let mut answers = answers.to_vec();
+    let mut guesses = 0;
+    let mut last_word = solver.make_a_move(&answers, full_words, guesses);
+
+    loop {
         guesses += 1;
-        let mask = program(last_word);
-        filter(Match::input(last_word, mask), &mut answers);
+        let mask = program(last_word.clone());
+        filter(&Match::input(last_word, mask.clone()), &mut answers);
 
-        match mask {
-            [Status::Exact, Status::Exact, Status::Exact, Status::Exact, Status::Exact] => break guesses,
-            _ => (),
+        if mask.iter().all(|status| matches!(status, Status::Exact)) {
+            return Some(guesses);
         }
 
-        // match input {
-        //     // "exit" => break,
-        //     // "invalid" => words.retain(|&x| x != last_word),
-        //     Signal::Success(buffer) => filter(Match::input(last_word, &buffer), &mut answers),
-        //     Signal::CtrlL => line_editor.clear_screen().unwrap(),
-        //     Signal::CtrlD | Signal::CtrlC => {
-        //         println!("Exiting...");
-        //         break
-        //     },
-        // }
-
-        // println!("{:?}", answers);
-
-        match answers.len() {
-            // 0 => panic!("No possible words left"),
-            0 => answers = full_words.clone(),
-            1 | 2 => last_word = answers[0],
-            _ => last_word = best_word(&full_words, &answers),
+        if guesses >= max_steps {
+            return None;
         }
+
+        if answers.is_empty() {
+            answers = full_words.iter().map(|word| (word.clone(), 1.0)).collect();
+        }
+
+        last_word = solver.make_a_move(&answers, full_words, guesses);
     }
 }